@@ -1,16 +1,23 @@
-use std::{collections::HashMap, sync::Arc};
+use std::sync::Arc;
 
-use bevy::{prelude::*, sprite::Rect};
+use bevy::{
+    prelude::*,
+    render::mesh::{Indices, PrimitiveTopology},
+    sprite::{ColorMesh2dBundle, Mesh2dHandle},
+};
 use rusty_spine::{
     animation_state::AnimationState, animation_state_data::AnimationStateData, atlas::Atlas,
-    error::Error, skeleton::Skeleton, skeleton_json::SkeletonJson,
+    error::Error, extension::set_create_texture_cb, skeleton::Skeleton, skeleton_json::SkeletonJson,
 };
 
+struct SpineTexture(Handle<Image>);
+
 #[derive(Component)]
 struct Spine {
     skeleton: Skeleton,
     animation_state: AnimationState,
-    slots: HashMap<String, Entity>,
+    // One mesh entity per slot, kept in draw order so z-ordering matches the skeleton.
+    slots: Vec<Entity>,
 }
 
 fn main() {
@@ -24,45 +31,45 @@ fn main() {
 fn setup(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
-    commands.spawn_bundle(Camera2dBundle::default());
-    match load_skeleton() {
-        Ok((skeleton, mut animation_state, atlas)) => {
-            let mut texture_atlas = TextureAtlas::new_empty(
-                asset_server.load("./spineboy-pro.png"),
-                Vec2::new(1534., 529.),
-            );
-            for region in atlas.regions().iter() {
-                let width = region.page().width() as f32;
-                let height = region.page().height() as f32;
-                let u = region.texture_region().u() as f32;
-                let v = region.texture_region().v() as f32;
-                let u2 = region.texture_region().u2() as f32;
-                let v2 = region.texture_region().v2() as f32;
-                texture_atlas.add_texture(Rect {
-                    min: Vec2::new(width * u, height * v),
-                    max: Vec2::new(width * u2, height * v2),
-                });
-            }
-            let texture_atlas_handle = texture_atlases.add(texture_atlas);
+    commands.spawn(Camera2dBundle::default());
 
+    // Textures are handed to us by path via the create-texture callback and stashed on the
+    // atlas page's renderer object, mirroring the pattern documented on `set_create_texture_cb`.
+    let asset_server = asset_server.clone();
+    set_create_texture_cb(move |atlas_page, path| {
+        atlas_page
+            .renderer_object()
+            .set(SpineTexture(asset_server.load(path.to_owned())));
+    });
+
+    match load_skeleton() {
+        Ok((mut skeleton, mut animation_state)) => {
             animation_state.set_animation_by_name(0, "hoverboard", true);
-            let mut slots = HashMap::new();
-            for slot in skeleton.slots().iter() {
-                let entity = commands
-                    .spawn_bundle(SpriteSheetBundle {
-                        sprite: TextureAtlasSprite {
-                            index: 0,
-                            ..Default::default()
-                        },
-                        texture_atlas: texture_atlas_handle.clone(),
-                        ..Default::default()
-                    })
-                    .id();
-                slots.insert(slot.data().name().to_owned(), entity);
-            }
-            commands.spawn().insert(Spine {
+
+            // Sized to the renderable count of the skeleton's current pose. If an animation's
+            // attachment timelines later show/hide slots, the live renderable count can drift
+            // from this, and the zip in `spine_update` will drop or leave stale mesh entities
+            // for the difference rather than grow/shrink `slots` to match.
+            let slot_count = skeleton.renderables().count();
+            let slots = (0..slot_count)
+                .map(|i| {
+                    commands
+                        .spawn(ColorMesh2dBundle {
+                            mesh: Mesh2dHandle(
+                                meshes.add(Mesh::new(PrimitiveTopology::TriangleList)),
+                            ),
+                            material: materials.add(ColorMaterial::default()),
+                            transform: Transform::from_xyz(0., 0., i as f32 * 0.01),
+                            ..default()
+                        })
+                        .id()
+                })
+                .collect();
+
+            commands.spawn(Spine {
                 skeleton,
                 animation_state,
                 slots,
@@ -74,10 +81,14 @@ fn setup(
     }
 }
 
-fn spine_update(mut spine_query: Query<&mut Spine>, mut children_query: Query<&mut Transform>) {
+fn spine_update(
+    mut spine_query: Query<&mut Spine>,
+    mut mesh_query: Query<(&Mesh2dHandle, &Handle<ColorMaterial>, &mut Transform)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
     let scale = 0.5;
     let offset = Vec2::new(0., -200.);
-    let mut z = 0.;
     for mut spine in spine_query.iter_mut() {
         let Spine {
             animation_state,
@@ -87,22 +98,44 @@ fn spine_update(mut spine_query: Query<&mut Spine>, mut children_query: Query<&m
         animation_state.update(0.016);
         animation_state.apply(skeleton);
         skeleton.update_world_transform();
-        for slot in skeleton.slots_mut().iter_mut() {
-            let slot_entity = slots.get(slot.data().name()).unwrap();
-            let mut slot_transform = children_query.get_mut(*slot_entity).unwrap();
-            slot_transform.translation = Vec3::new(
-                slot.bone().world_x() * scale,
-                slot.bone().world_y() * scale,
-                z,
-            ) + offset.extend(0.);
-            slot_transform.rotation =
-                Quat::from_axis_angle(Vec3::Z, slot.bone().rotation().to_radians());
+
+        for (renderable, slot_entity) in skeleton.renderables().zip(slots.iter()) {
+            let Ok((mesh_handle, material_handle, mut transform)) =
+                mesh_query.get_mut(*slot_entity)
+            else {
+                continue;
+            };
+            transform.translation.x = offset.x;
+            transform.translation.y = offset.y;
+            transform.scale = Vec3::new(scale, scale, 1.);
+
+            if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+                let positions: Vec<[f32; 3]> = renderable
+                    .positions
+                    .iter()
+                    .map(|[x, y]| [*x, *y, 0.])
+                    .collect();
+                mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+                mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, renderable.uvs.clone());
+                mesh.set_indices(Some(Indices::U16(renderable.indices.clone())));
+            }
+
+            if let Some(material) = materials.get_mut(material_handle) {
+                material.color = Color::rgba(
+                    renderable.color.r,
+                    renderable.color.g,
+                    renderable.color.b,
+                    renderable.color.a,
+                );
+                if let Some(texture) = unsafe { renderable.renderer_object.get::<SpineTexture>() } {
+                    material.texture = Some(texture.0.clone());
+                }
+            }
         }
-        z += 0.01;
     }
 }
 
-fn load_skeleton() -> Result<(Skeleton, AnimationState, Arc<Atlas>), Error> {
+fn load_skeleton() -> Result<(Skeleton, AnimationState), Error> {
     let file = include_bytes!("../spineboy/spineboy-pro.atlas");
     let dir = "./";
     let atlas = Arc::new(Atlas::new(file, dir)?);
@@ -112,5 +145,5 @@ fn load_skeleton() -> Result<(Skeleton, AnimationState, Arc<Atlas>), Error> {
     let animation_state_data = AnimationStateData::new(skeleton_data.clone());
     let skeleton = Skeleton::new(skeleton_data)?;
     let animation_state = AnimationState::new(Arc::new(animation_state_data));
-    Ok((skeleton, animation_state, atlas))
+    Ok((skeleton, animation_state))
 }
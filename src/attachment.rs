@@ -0,0 +1,82 @@
+use crate::{
+    c::{
+        spAtlasRegion, spAttachment, spAttachmentType, spMeshAttachment, spRegionAttachment,
+        SP_ATTACHMENT_BOUNDING_BOX, SP_ATTACHMENT_CLIPPING, SP_ATTACHMENT_MESH,
+        SP_ATTACHMENT_PATH, SP_ATTACHMENT_POINT, SP_ATTACHMENT_REGION,
+    },
+    c_interface::{NewFromPtr, RendererObject},
+    sync_ptr::SyncPtr,
+};
+
+/// Mirrors spine-c's `spAttachmentType`, identifying what kind of geometry (if any) an
+/// [Attachment] contributes to rendering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachmentType {
+    Region,
+    Mesh,
+    BoundingBox,
+    Path,
+    Point,
+    Clipping,
+}
+
+impl From<spAttachmentType> for AttachmentType {
+    fn from(c_attachment_type: spAttachmentType) -> Self {
+        match c_attachment_type {
+            SP_ATTACHMENT_BOUNDING_BOX => AttachmentType::BoundingBox,
+            SP_ATTACHMENT_MESH => AttachmentType::Mesh,
+            SP_ATTACHMENT_PATH => AttachmentType::Path,
+            SP_ATTACHMENT_POINT => AttachmentType::Point,
+            SP_ATTACHMENT_CLIPPING => AttachmentType::Clipping,
+            _ => AttachmentType::Region,
+        }
+    }
+}
+
+/// A slot's currently active attachment.
+///
+/// Only region and mesh attachments carry a texture; bounding box, path, point, and clipping
+/// attachments exist purely to drive gameplay or rendering logic and have no [renderer_object](
+/// Attachment::renderer_object).
+#[derive(Debug)]
+pub struct Attachment {
+    c_attachment: SyncPtr<spAttachment>,
+}
+
+impl Attachment {
+    pub fn attachment_type(&self) -> AttachmentType {
+        AttachmentType::from(unsafe { (*self.c_attachment.0).type_ })
+    }
+
+    /// The renderer object set on the atlas page of this attachment's texture region by
+    /// [set_create_texture_cb](crate::extension::set_create_texture_cb), if this is a region or
+    /// mesh attachment.
+    pub fn renderer_object(&self) -> Option<RendererObject> {
+        // The attachment's own `rendererObject` points at the `spAtlasRegion` it was loaded from;
+        // the texture is stored on that region's page.
+        let c_renderer_object = match self.attachment_type() {
+            AttachmentType::Region => unsafe {
+                (*(self.c_attachment.0 as *mut spRegionAttachment)).rendererObject
+            },
+            AttachmentType::Mesh => unsafe {
+                (*(self.c_attachment.0 as *mut spMeshAttachment)).rendererObject
+            },
+            _ => return None,
+        };
+        let c_atlas_region = c_renderer_object as *mut spAtlasRegion;
+        if c_atlas_region.is_null() {
+            return Some(unsafe { RendererObject::from_ptr(c_renderer_object) });
+        }
+        Some(unsafe { RendererObject::from_ptr((*(*c_atlas_region).page).rendererObject) })
+    }
+
+    c_ptr!(c_attachment, spAttachment);
+}
+
+impl NewFromPtr<spAttachment> for Attachment {
+    unsafe fn new_from_ptr(c_attachment: *mut spAttachment) -> Self {
+        Self {
+            c_attachment: SyncPtr(c_attachment),
+        }
+    }
+}
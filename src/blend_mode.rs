@@ -0,0 +1,22 @@
+use crate::c::{spBlendMode, SP_BLEND_MODE_ADDITIVE, SP_BLEND_MODE_MULTIPLY, SP_BLEND_MODE_SCREEN};
+
+/// Mirrors spine-c's `spBlendMode`, controlling how a slot's attachment is composited over
+/// whatever has already been drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    Normal,
+    Additive,
+    Multiply,
+    Screen,
+}
+
+impl From<spBlendMode> for BlendMode {
+    fn from(c_blend_mode: spBlendMode) -> Self {
+        match c_blend_mode {
+            SP_BLEND_MODE_ADDITIVE => BlendMode::Additive,
+            SP_BLEND_MODE_MULTIPLY => BlendMode::Multiply,
+            SP_BLEND_MODE_SCREEN => BlendMode::Screen,
+            _ => BlendMode::Normal,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Wraps spine-c's `spSkeletonClipping`, which masks other attachments against an arbitrary
+//! polygon defined by a clipping attachment.
+//!
+//! A [SkeletonClipping] tracks at most one active clip region at a time, the way the C runtime
+//! does: [clip_start](SkeletonClipping::clip_start) begins masking at a clipping attachment's
+//! slot, [clip_triangles](SkeletonClipping::clip_triangles) re-triangulates subsequent
+//! attachments' geometry against it via Sutherland-Hodgman polygon clipping, and
+//! [clip_end_slot](SkeletonClipping::clip_end_slot) pops the region once the slot named by the
+//! clipping attachment's `end_slot` is reached.
+
+use crate::{
+    c::{
+        c_float, c_int, spClippingAttachment, spSkeletonClipping, spSkeletonClipping_clipEnd,
+        spSkeletonClipping_clipEnd2, spSkeletonClipping_clipStart,
+        spSkeletonClipping_clipTriangles, spSkeletonClipping_create,
+        spSkeletonClipping_dispose, spSkeletonClipping_isClipping, spSlot,
+    },
+    draw::CullDirection,
+    sync_ptr::SyncPtr,
+};
+
+/// Re-triangulated geometry produced by [SkeletonClipping::clip_triangles].
+pub struct ClippedTriangles {
+    pub positions: Vec<[f32; 2]>,
+    pub uvs: Vec<[f32; 2]>,
+    pub indices: Vec<u16>,
+}
+
+/// Tracks the active clip region while walking a skeleton's draw order.
+#[derive(Debug)]
+pub struct SkeletonClipping {
+    c_skeleton_clipping: SyncPtr<spSkeletonClipping>,
+}
+
+impl SkeletonClipping {
+    pub fn new() -> Self {
+        Self {
+            c_skeleton_clipping: SyncPtr(unsafe { spSkeletonClipping_create() }),
+        }
+    }
+
+    /// Begins clipping against the polygon of the clipping attachment active on `c_slot`.
+    pub(crate) fn clip_start(&mut self, c_slot: *mut spSlot, c_clip: *mut spClippingAttachment) {
+        unsafe {
+            spSkeletonClipping_clipStart(self.c_skeleton_clipping.0, c_slot, c_clip);
+        }
+    }
+
+    /// Whether a clip region is currently active.
+    pub fn is_clipping(&self) -> bool {
+        unsafe { spSkeletonClipping_isClipping(self.c_skeleton_clipping.0) != 0 }
+    }
+
+    /// Intersects the given attachment geometry against the active clip polygon and
+    /// re-triangulates the result, flipping the winding of the output triangles when
+    /// `cull_direction` is [CullDirection::CounterClockwise]. Returns `None` when the geometry
+    /// is entirely outside the clip polygon.
+    pub fn clip_triangles(
+        &mut self,
+        positions: &[[f32; 2]],
+        uvs: &[[f32; 2]],
+        indices: &[u16],
+        cull_direction: CullDirection,
+    ) -> Option<ClippedTriangles> {
+        let mut vertices: Vec<c_float> = positions.iter().flat_map(|p| [p[0], p[1]]).collect();
+        let mut flat_uvs: Vec<c_float> = uvs.iter().flat_map(|uv| [uv[0], uv[1]]).collect();
+        let mut triangles: Vec<u16> = indices.to_vec();
+        unsafe {
+            spSkeletonClipping_clipTriangles(
+                self.c_skeleton_clipping.0,
+                vertices.as_mut_ptr(),
+                vertices.len() as c_int,
+                triangles.as_mut_ptr(),
+                triangles.len() as c_int,
+                flat_uvs.as_mut_ptr(),
+            );
+            let c_clipping = &*self.c_skeleton_clipping.0;
+            let clipped_triangles = &*c_clipping.clippedTriangles;
+            if clipped_triangles.size == 0 {
+                return None;
+            }
+            let clipped_vertices = &*c_clipping.clippedVertices;
+            let clipped_uvs = &*c_clipping.clippedUVs;
+            let vertex_count = clipped_vertices.size as usize / 2;
+            let positions = (0..vertex_count)
+                .map(|i| {
+                    [
+                        *clipped_vertices.items.add(i * 2),
+                        *clipped_vertices.items.add(i * 2 + 1),
+                    ]
+                })
+                .collect();
+            let uvs = (0..vertex_count)
+                .map(|i| {
+                    [
+                        *clipped_uvs.items.add(i * 2),
+                        *clipped_uvs.items.add(i * 2 + 1),
+                    ]
+                })
+                .collect();
+            let mut indices: Vec<u16> = (0..clipped_triangles.size as usize)
+                .map(|i| *clipped_triangles.items.add(i))
+                .collect();
+            if cull_direction == CullDirection::CounterClockwise {
+                for triangle in indices.chunks_mut(3) {
+                    triangle.swap(1, 2);
+                }
+            }
+            Some(ClippedTriangles {
+                positions,
+                uvs,
+                indices,
+            })
+        }
+    }
+
+    /// Pops the clip region if `c_slot` is the slot named by the active clipping attachment's
+    /// `end_slot`. Call this once for every slot in draw order, clipping attachments included.
+    pub(crate) fn clip_end_slot(&mut self, c_slot: *mut spSlot) {
+        unsafe {
+            spSkeletonClipping_clipEnd(self.c_skeleton_clipping.0, c_slot);
+        }
+    }
+
+    /// Unconditionally clears the active clip region, e.g. once draw order iteration finishes.
+    pub fn clip_end(&mut self) {
+        unsafe {
+            spSkeletonClipping_clipEnd2(self.c_skeleton_clipping.0);
+        }
+    }
+}
+
+impl Default for SkeletonClipping {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SkeletonClipping {
+    fn drop(&mut self) {
+        unsafe {
+            spSkeletonClipping_dispose(self.c_skeleton_clipping.0);
+        }
+    }
+}
@@ -1,7 +1,11 @@
+mod clipping;
 mod combined;
+mod renderable;
 mod simple;
 
+pub use clipping::*;
 pub use combined::*;
+pub use renderable::*;
 pub use simple::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,220 @@
+//! Triangulated, GPU-ready geometry for a skeleton's attachments.
+//!
+//! [Skeleton::renderables](crate::skeleton::Skeleton::renderables) walks the skeleton's draw
+//! order and, for each slot with a region or mesh attachment, computes world-space vertex
+//! positions, UVs, and a tint color ready to be uploaded to a vertex buffer. Bounding box, path,
+//! point, and clipping attachments produce no geometry on their own and are skipped by the
+//! iterator, as is any slot whose geometry lies entirely outside an active clip region.
+
+use crate::{
+    attachment::{Attachment, AttachmentType},
+    c::{
+        c_int, spAttachment, spBone, spClippingAttachment, spMeshAttachment, spRegionAttachment,
+        spRegionAttachment_computeWorldVertices, spSkeleton, spSlot,
+        spVertexAttachment_computeWorldVertices,
+    },
+    c_interface::{NewFromPtr, RendererObject},
+    color::Color,
+    draw::{CullDirection, SkeletonClipping},
+    skeleton::Skeleton,
+};
+
+/// The fixed winding order used to triangulate a region attachment's four corners.
+const REGION_INDICES: [u16; 6] = [0, 1, 2, 2, 3, 0];
+
+/// Triangulated geometry for a single drawn slot, ready to hand to a renderer.
+pub struct Renderable {
+    pub positions: Vec<[f32; 2]>,
+    pub uvs: Vec<[f32; 2]>,
+    pub indices: Vec<u16>,
+    pub color: Color,
+    pub renderer_object: RendererObject,
+}
+
+/// Iterates a skeleton's draw order, producing a [Renderable] for each slot whose attachment
+/// has geometry.
+///
+/// Created with [Skeleton::renderables](crate::skeleton::Skeleton::renderables).
+pub struct RenderableIterator<'a> {
+    c_skeleton: *mut spSkeleton,
+    index: c_int,
+    slot_count: c_int,
+    clipping: &'a mut SkeletonClipping,
+}
+
+impl<'a> RenderableIterator<'a> {
+    pub(crate) fn new(skeleton: &'a mut Skeleton) -> Self {
+        let c_skeleton = skeleton.c_ptr();
+        let slot_count = unsafe { (*c_skeleton).slotsCount };
+        Self {
+            c_skeleton,
+            index: 0,
+            slot_count,
+            clipping: skeleton.clipping_mut(),
+        }
+    }
+}
+
+impl<'a> Iterator for RenderableIterator<'a> {
+    type Item = Renderable;
+
+    fn next(&mut self) -> Option<Renderable> {
+        while self.index < self.slot_count {
+            let i = self.index;
+            self.index += 1;
+            let c_slot = unsafe { *(*self.c_skeleton).drawOrder.offset(i as isize) };
+            let renderable = renderable_for_slot(c_slot, &mut *self.clipping);
+            self.clipping.clip_end_slot(c_slot);
+            if renderable.is_some() {
+                return renderable;
+            }
+        }
+        // Draw order is exhausted; force-clear any clip region left open by a malformed skeleton.
+        self.clipping.clip_end();
+        None
+    }
+}
+
+fn renderable_for_slot(c_slot: *mut spSlot, clipping: &mut SkeletonClipping) -> Option<Renderable> {
+    let c_attachment = unsafe { (*c_slot).attachment };
+    if c_attachment.is_null() {
+        return None;
+    }
+    match AttachmentType::from(unsafe { (*c_attachment).type_ }) {
+        AttachmentType::Region => {
+            clip_renderable(region_renderable(c_slot, c_attachment), c_slot, clipping)
+        }
+        AttachmentType::Mesh => {
+            clip_renderable(mesh_renderable(c_slot, c_attachment), c_slot, clipping)
+        }
+        AttachmentType::Clipping => {
+            clipping.clip_start(c_slot, c_attachment as *mut spClippingAttachment);
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Applies the active clip region (if any) to `renderable`'s geometry, dropping it entirely if
+/// nothing survives clipping.
+fn clip_renderable(
+    renderable: Renderable,
+    c_slot: *mut spSlot,
+    clipping: &mut SkeletonClipping,
+) -> Option<Renderable> {
+    if !clipping.is_clipping() {
+        return Some(renderable);
+    }
+    let cull_direction = bone_cull_direction(unsafe { (*c_slot).bone });
+    let clipped = clipping.clip_triangles(
+        &renderable.positions,
+        &renderable.uvs,
+        &renderable.indices,
+        cull_direction,
+    )?;
+    Some(Renderable {
+        positions: clipped.positions,
+        uvs: clipped.uvs,
+        indices: clipped.indices,
+        ..renderable
+    })
+}
+
+/// The winding direction of a bone's world transform, used to keep clipped triangles front-facing
+/// when an ancestor bone has a negative (mirrored) scale.
+fn bone_cull_direction(c_bone: *mut spBone) -> CullDirection {
+    let (a, b, c, d) = unsafe { ((*c_bone).a, (*c_bone).b, (*c_bone).c, (*c_bone).d) };
+    if a * d - b * c < 0. {
+        CullDirection::CounterClockwise
+    } else {
+        CullDirection::Clockwise
+    }
+}
+
+fn region_renderable(c_slot: *mut spSlot, c_attachment: *mut spAttachment) -> Renderable {
+    let c_region = c_attachment as *mut spRegionAttachment;
+    let c_bone = unsafe { (*c_slot).bone };
+    let mut world_vertices = [0f32; 8];
+    unsafe {
+        spRegionAttachment_computeWorldVertices(
+            c_region,
+            c_bone,
+            world_vertices.as_mut_ptr(),
+            0,
+            2,
+        );
+    }
+    let positions = (0..4)
+        .map(|i| [world_vertices[i * 2], world_vertices[i * 2 + 1]])
+        .collect();
+    let uvs = unsafe { (*c_region).uvs };
+    let uvs = (0..4)
+        .map(|i| [unsafe { *uvs.offset(i as isize * 2) }, unsafe {
+            *uvs.offset(i as isize * 2 + 1)
+        }])
+        .collect();
+    Renderable {
+        positions,
+        uvs,
+        indices: REGION_INDICES.to_vec(),
+        color: slot_color(c_slot, unsafe { (*c_region).color }),
+        renderer_object: unsafe { Attachment::new_from_ptr(c_attachment) }
+            .renderer_object()
+            .unwrap(),
+    }
+}
+
+fn mesh_renderable(c_slot: *mut spSlot, c_attachment: *mut spAttachment) -> Renderable {
+    let c_mesh = c_attachment as *mut spMeshAttachment;
+    let world_vertices_length = unsafe { (*c_mesh).super_0.worldVerticesLength } as usize;
+    let mut world_vertices = vec![0f32; world_vertices_length];
+    unsafe {
+        spVertexAttachment_computeWorldVertices(
+            &mut (*c_mesh).super_0,
+            c_slot,
+            0,
+            world_vertices_length as c_int,
+            world_vertices.as_mut_ptr(),
+            0,
+            2,
+        );
+    }
+    let vertex_count = world_vertices_length / 2;
+    let positions = (0..vertex_count)
+        .map(|i| [world_vertices[i * 2], world_vertices[i * 2 + 1]])
+        .collect();
+    let uvs = unsafe { (*c_mesh).uvs };
+    let uvs = (0..vertex_count)
+        .map(|i| [unsafe { *uvs.offset(i as isize * 2) }, unsafe {
+            *uvs.offset(i as isize * 2 + 1)
+        }])
+        .collect();
+    let triangles = unsafe { (*c_mesh).triangles };
+    let triangles_count = unsafe { (*c_mesh).trianglesCount } as usize;
+    let indices = (0..triangles_count)
+        .map(|i| unsafe { *triangles.offset(i as isize) })
+        .collect();
+    Renderable {
+        positions,
+        uvs,
+        indices,
+        color: slot_color(c_slot, unsafe { (*c_mesh).color }),
+        renderer_object: unsafe { Attachment::new_from_ptr(c_attachment) }
+            .renderer_object()
+            .unwrap(),
+    }
+}
+
+/// Combines the skeleton, slot, and attachment tint colors the way the spine-c runtime does when
+/// rendering a region or mesh attachment.
+fn slot_color(c_slot: *mut spSlot, attachment_color: crate::c::spColor) -> Color {
+    let c_skeleton = unsafe { (*(*c_slot).bone).skeleton };
+    let skeleton_color = unsafe { (*c_skeleton).color };
+    let slot_color = unsafe { (*c_slot).color };
+    Color::new_rgba(
+        skeleton_color.r * slot_color.r * attachment_color.r,
+        skeleton_color.g * slot_color.g * attachment_color.g,
+        skeleton_color.b * slot_color.b * attachment_color.b,
+        skeleton_color.a * slot_color.a * attachment_color.a,
+    )
+}
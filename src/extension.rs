@@ -11,19 +11,25 @@
 //! * `void _spAtlasPage_disposeTexture (spAtlasPage* self);`
 //! * `char* _spUtil_readFile (const char* path, int* length);`
 //!
-//! They can be set with the functions found on this page.
+//! They can be set with the functions found on this page. Texture creation can optionally be
+//! scoped to a single [Atlas](crate::atlas::Atlas) with [set_create_texture_cb_for_atlas], for
+//! applications juggling more than one texture source.
 //!
 //! You can read more about these functions on the
 //! [spine-c Runtime Docs](http://en.esotericsoftware.com/spine-c#Integrating-spine-c-in-your-engine).
 
+use std::any::Any;
+use std::collections::HashMap;
 use std::ffi::CStr;
 use std::fs::read;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, Once};
 
 use crate::c::{c_int, c_void, size_t};
 use crate::c_interface::NewFromPtr;
 use crate::{
-    atlas::AtlasPage,
+    atlas::{Atlas, AtlasPage},
     c::{c_char, spAtlasPage},
 };
 
@@ -32,6 +38,8 @@ pub(crate) struct Extension {
     create_texture_cb: Option<Box<dyn Fn(&mut AtlasPage, &str)>>,
     dispose_texture_cb: Option<Box<dyn Fn(&mut AtlasPage)>>,
     read_file_cb: Option<Box<dyn Fn(&str) -> Option<Vec<u8>>>>,
+    atlas_create_texture_cbs: HashMap<usize, Box<dyn Fn(&mut AtlasPage, &str)>>,
+    panics: Vec<String>,
 }
 
 impl Extension {
@@ -46,6 +54,32 @@ impl Extension {
             singleton.clone()
         }
     }
+
+    fn record_panic(&mut self, panic: Box<dyn Any + Send>) {
+        self.panics.push(panic_message(panic));
+    }
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic in extension callback".to_owned()
+    }
+}
+
+/// Returns and clears any panic messages caught from user-supplied extension callbacks.
+///
+/// The FFI trampolines which call into [set_create_texture_cb], [set_dispose_texture_cb], and
+/// [set_read_file_cb] catch panics at the C boundary to avoid unwinding across foreign stack
+/// frames, since doing so is undefined behavior. Call this function to check whether any of
+/// those callbacks panicked.
+pub fn take_panics() -> Vec<String> {
+    let singleton = Extension::singleton();
+    let mut extension = singleton.lock().unwrap();
+    std::mem::take(&mut extension.panics)
 }
 
 /// Set `_spAtlasPage_createTexture`
@@ -74,6 +108,57 @@ where
     extension.create_texture_cb = Some(Box::new(create_texture_cb));
 }
 
+/// Set `_spAtlasPage_createTexture` for pages belonging to a specific [Atlas] only.
+///
+/// Unlike [set_create_texture_cb], which installs one callback shared by every atlas in the
+/// process, this associates the callback with `atlas` by stamping a unique marker onto the
+/// atlas's `rendererObject`. When `_spAtlasPage_createTexture` fires for one of the atlas's
+/// pages, it looks the marker back up and dispatches to this callback instead of the global one.
+/// Useful when an application loads atlases for more than one rendering backend, or mixes
+/// disk-backed and embedded texture sources.
+pub fn set_create_texture_cb_for_atlas<F>(atlas: &Atlas, create_texture_cb: F)
+where
+    F: Fn(&mut AtlasPage, &str) + 'static,
+{
+    static NEXT_KEY: AtomicUsize = AtomicUsize::new(1);
+    let key = NEXT_KEY.fetch_add(1, Ordering::Relaxed);
+    let singleton = Extension::singleton();
+    let mut extension = singleton.lock().unwrap();
+    // Re-registering the same atlas (e.g. hot-reloading a skin) must not orphan the entry its
+    // previous registration left behind.
+    unregister_atlas_locked(&mut extension, atlas.c_ptr());
+    unsafe {
+        (*atlas.c_ptr()).rendererObject = key as *mut c_void;
+    }
+    extension
+        .atlas_create_texture_cbs
+        .insert(key, Box::new(create_texture_cb));
+}
+
+/// Removes any per-atlas texture loader registered for `atlas` and clears its `rendererObject`
+/// marker.
+///
+/// [set_create_texture_cb_for_atlas] calls this automatically when an atlas is re-registered, so
+/// it only needs to be called directly when an atlas registered with a per-atlas loader is being
+/// dropped: `Atlas`'s `Drop` implementation does not (yet) call this on its own, so failing to
+/// call it before dropping such an atlas leaks its boxed callback in this process-wide registry
+/// for the remaining lifetime of the process.
+pub fn unregister_atlas(atlas: &Atlas) {
+    let singleton = Extension::singleton();
+    let mut extension = singleton.lock().unwrap();
+    unregister_atlas_locked(&mut extension, atlas.c_ptr());
+}
+
+fn unregister_atlas_locked(extension: &mut Extension, c_atlas: *mut crate::c::spAtlas) {
+    let key = unsafe { (*c_atlas).rendererObject as usize };
+    if key != 0 {
+        extension.atlas_create_texture_cbs.remove(&key);
+        unsafe {
+            (*c_atlas).rendererObject = std::ptr::null_mut();
+        }
+    }
+}
+
 /// Set `_spAtlasPage_disposeTexture`
 ///
 /// For an example, see [set_create_texture_cb](fn.set_create_texture_cb.html).
@@ -112,13 +197,30 @@ where
 #[no_mangle]
 extern "C" fn _spAtlasPage_createTexture(c_atlas_page: *mut spAtlasPage, c_path: *const c_char) {
     let singleton = Extension::singleton();
-    let extension = singleton.lock().unwrap();
-    if let Some(cb) = &extension.create_texture_cb {
-        unsafe {
+    let mut extension = singleton.lock().unwrap();
+    // Prefer a loader registered for this page's owning atlas over the global one.
+    let atlas_key = unsafe {
+        let c_atlas = (*c_atlas_page).atlas;
+        if c_atlas.is_null() {
+            0
+        } else {
+            (*c_atlas).rendererObject as usize
+        }
+    };
+    let cb = extension
+        .atlas_create_texture_cbs
+        .get(&atlas_key)
+        .or(extension.create_texture_cb.as_ref());
+    if let Some(cb) = cb {
+        let result = catch_unwind(AssertUnwindSafe(|| unsafe {
             cb(
                 &mut AtlasPage::new_from_ptr(c_atlas_page),
                 CStr::from_ptr(c_path).to_str().unwrap(),
             );
+        }));
+        if let Err(panic) = result {
+            // A no-op is the safe default: the texture simply fails to load.
+            extension.record_panic(panic);
         }
     }
 }
@@ -126,10 +228,13 @@ extern "C" fn _spAtlasPage_createTexture(c_atlas_page: *mut spAtlasPage, c_path:
 #[no_mangle]
 extern "C" fn _spAtlasPage_disposeTexture(c_atlas_page: *mut spAtlasPage) {
     let singleton = Extension::singleton();
-    let extension = singleton.lock().unwrap();
+    let mut extension = singleton.lock().unwrap();
     if let Some(cb) = &extension.dispose_texture_cb {
-        unsafe {
+        let result = catch_unwind(AssertUnwindSafe(|| unsafe {
             cb(&mut AtlasPage::new_from_ptr(c_atlas_page));
+        }));
+        if let Err(panic) = result {
+            extension.record_panic(panic);
         }
     }
 }
@@ -142,17 +247,26 @@ extern "C" {
 #[no_mangle]
 extern "C" fn _spUtil_readFile(c_path: *const c_char, c_length: *mut c_int) -> *mut c_char {
     let singleton = Extension::singleton();
-    let extension = singleton.lock().unwrap();
+    let mut extension = singleton.lock().unwrap();
     if let Some(cb) = &extension.read_file_cb {
-        if let Some(data) = cb(unsafe { CStr::from_ptr(c_path).to_str().unwrap() }) {
-            unsafe {
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            cb(unsafe { CStr::from_ptr(c_path).to_str().unwrap() })
+        }));
+        match result {
+            Ok(Some(data)) => unsafe {
                 *c_length = data.len() as c_int;
                 let c_data = spine_malloc(data.len() as size_t);
                 spine_memcpy(c_data, data.as_ptr() as *const c_void, data.len() as size_t);
                 c_data as *mut c_char
+            },
+            Ok(None) => std::ptr::null_mut(),
+            Err(panic) => {
+                extension.record_panic(panic);
+                unsafe {
+                    *c_length = 0;
+                }
+                std::ptr::null_mut()
             }
-        } else {
-            std::ptr::null_mut()
         }
     } else {
         let str = unsafe { CStr::from_ptr(c_path).to_str().unwrap().to_owned() };
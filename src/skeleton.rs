@@ -2,9 +2,15 @@ use std::{ffi::CString, sync::Arc};
 
 use crate::{
     bone::Bone,
-    c::{spSkeleton, spSkeleton_create, spSkeleton_findBone, spSkeleton_updateWorldTransform},
+    c::{
+        spSkeleton, spSkeleton_create, spSkeleton_findBone, spSkeleton_findSlot,
+        spSkeleton_updateWorldTransform,
+    },
+    c_interface::NewFromPtr,
+    draw::{RenderableIterator, SkeletonClipping},
     error::Error,
     skeleton_data::SkeletonData,
+    slot::Slot,
     sync_ptr::SyncPtr,
 };
 
@@ -13,6 +19,8 @@ pub struct Skeleton {
     c_skeleton: SyncPtr<spSkeleton>,
     _skeleton_data: Arc<SkeletonData>,
     bones: Vec<Bone>,
+    slots: Vec<Slot>,
+    clipping: SkeletonClipping,
 }
 
 impl Skeleton {
@@ -25,10 +33,19 @@ impl Skeleton {
                 bones.push(Bone::new(*(*c_skeleton).bones.offset(i as isize)));
             }
         }
+        let mut slots = vec![];
+        let slot_count = unsafe { (*c_skeleton).slotsCount };
+        for i in 0..slot_count {
+            unsafe {
+                slots.push(Slot::new_from_ptr(*(*c_skeleton).slots.offset(i as isize)));
+            }
+        }
         Ok(Self {
             c_skeleton: SyncPtr(c_skeleton),
             _skeleton_data: skeleton_data,
             bones,
+            slots,
+            clipping: SkeletonClipping::new(),
         })
     }
 
@@ -59,5 +76,40 @@ impl Skeleton {
         }
     }
 
+    pub fn slots(&self) -> &Vec<Slot> {
+        &self.slots
+    }
+
+    pub fn slots_mut(&mut self) -> &mut Vec<Slot> {
+        &mut self.slots
+    }
+
+    pub fn find_slot(&self, name: &str) -> Option<&Slot> {
+        if let Ok(c_name) = CString::new(name) {
+            let slot = unsafe { spSkeleton_findSlot(self.c_skeleton.0, c_name.as_ptr()) };
+            if !slot.is_null() {
+                unsafe { self.slots.get((*(*slot).data).index as usize) }
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over this skeleton's draw order, producing triangulated,
+    /// GPU-ready geometry for each slot with a region or mesh attachment. Slots whose geometry
+    /// is clipped away entirely by a clipping attachment are skipped.
+    ///
+    /// Reuses the skeleton's own [SkeletonClipping] rather than allocating a new one on every
+    /// call, since this is meant to be called once per frame.
+    pub fn renderables(&mut self) -> RenderableIterator<'_> {
+        RenderableIterator::new(self)
+    }
+
+    pub(crate) fn clipping_mut(&mut self) -> &mut SkeletonClipping {
+        &mut self.clipping
+    }
+
     c_ptr!(c_skeleton, spSkeleton);
 }
\ No newline at end of file
@@ -0,0 +1,80 @@
+use std::ffi::CStr;
+
+use crate::{
+    attachment::Attachment,
+    blend_mode::BlendMode,
+    bone::Bone,
+    c::{spSlot, spSlotData},
+    c_interface::NewFromPtr,
+    sync_ptr::SyncPtr,
+};
+
+/// The setup-pose data shared by every skeleton instance for a given slot.
+#[derive(Debug)]
+pub struct SlotData {
+    c_slot_data: SyncPtr<spSlotData>,
+}
+
+impl SlotData {
+    pub fn name(&self) -> &str {
+        unsafe {
+            CStr::from_ptr((*self.c_slot_data.0).name)
+                .to_str()
+                .unwrap()
+        }
+    }
+
+    pub fn blend_mode(&self) -> BlendMode {
+        BlendMode::from(unsafe { (*self.c_slot_data.0).blendMode })
+    }
+
+    c_ptr!(c_slot_data, spSlotData);
+}
+
+impl NewFromPtr<spSlotData> for SlotData {
+    unsafe fn new_from_ptr(c_slot_data: *mut spSlotData) -> Self {
+        Self {
+            c_slot_data: SyncPtr(c_slot_data),
+        }
+    }
+}
+
+/// A slot attaches an [Attachment] to a [Bone] and determines how it is drawn.
+#[derive(Debug)]
+pub struct Slot {
+    c_slot: SyncPtr<spSlot>,
+    bone: Bone,
+    data: SlotData,
+}
+
+impl Slot {
+    pub fn bone(&self) -> &Bone {
+        &self.bone
+    }
+
+    pub fn data(&self) -> &SlotData {
+        &self.data
+    }
+
+    /// The attachment currently active on this slot, if any.
+    pub fn attachment(&self) -> Option<Attachment> {
+        let c_attachment = unsafe { (*self.c_slot.0).attachment };
+        if c_attachment.is_null() {
+            None
+        } else {
+            Some(unsafe { Attachment::new_from_ptr(c_attachment) })
+        }
+    }
+
+    c_ptr!(c_slot, spSlot);
+}
+
+impl NewFromPtr<spSlot> for Slot {
+    unsafe fn new_from_ptr(c_slot: *mut spSlot) -> Self {
+        Self {
+            bone: Bone::new((*c_slot).bone),
+            data: SlotData::new_from_ptr((*c_slot).data),
+            c_slot: SyncPtr(c_slot),
+        }
+    }
+}